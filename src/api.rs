@@ -11,21 +11,28 @@
 // without cluttering the core (serial.rs)) logic
 // note: input validation is to be handled by the frontend
 
-use crate::serial::SerialConnection;
-use serialport::{SerialPortBuilder, SerialPortInfo};
+use crate::parser::Frame;
+use crate::retry::RetryPolicy;
+use crate::serial::{read_target, SerialConnection};
+use crate::transport::Transport;
+use serialport::{DataBits, Parity, SerialPort, SerialPortBuilder, SerialPortInfo, StopBits};
+use std::collections::HashMap;
 use std::io::{self, Read, Write};
-use std::sync::{Arc, Mutex, MutexGuard};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, Weak};
+use std::thread;
 
-/// mutex-protected shared connection object
-pub type SharedConnection = Arc<Mutex<Option<SerialConnection>>>;
+/// mutex-protected shared connection object, generic over any [`Transport`]
+/// so the retry/timeout logic below can be driven by a `MockTransport` in
+/// tests; defaults to the real [`SerialConnection`] for everyday callers
+pub type SharedConnection<T = SerialConnection> = Arc<Mutex<Option<T>>>;
 
 use log::{error, info, warn};
 use std::time::{Duration, Instant}; // Make sure to add `log` to your Cargo.toml
 
 /// lock a shared connection
-fn lock_connection(
-    connection: &SharedConnection,
-) -> io::Result<MutexGuard<Option<SerialConnection>>> {
+fn lock_connection<T>(connection: &SharedConnection<T>) -> io::Result<MutexGuard<Option<T>>> {
     connection.lock().map_err(|e| {
         io::Error::new(
             io::ErrorKind::Other,
@@ -34,6 +41,21 @@ fn lock_connection(
     })
 }
 
+/// stop flags of the [`StreamHandle`]s outstanding per `SharedConnection`,
+/// keyed by the `Arc`'s address; lets [`disconnect`] signal any background
+/// `subscribe` reader threads still running against it to stop, since they
+/// work off their own `try_clone()`'d port rather than the mutex `disconnect`
+/// takes. entries are `Weak` so a handle that's already stopped (or dropped)
+/// without the owner ever calling `disconnect` doesn't leak here.
+fn stream_registry() -> &'static Mutex<HashMap<usize, Vec<Weak<AtomicBool>>>> {
+    static REGISTRY: OnceLock<Mutex<HashMap<usize, Vec<Weak<AtomicBool>>>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn shared_conn_key<T>(shared_conn: &SharedConnection<T>) -> usize {
+    Arc::as_ptr(shared_conn) as usize
+}
+
 /// list available serial ports
 ///
 /// @return io::Result<Vec<SerialPortInfo>> - result of the operation
@@ -77,10 +99,21 @@ pub fn connect(shared_conn: &SharedConnection, port: SerialPortBuilder) -> io::R
 
 /// disconnect from a serial port
 ///
-/// @param shared_conn: &SharedConnection - shared connection object
+/// also signals the stop flag of any [`StreamHandle`] still outstanding
+/// from a [`subscribe`] call against this connection, so a background
+/// reader thread doesn't keep reading through a port the caller now
+/// believes is closed
+///
+/// @param shared_conn: &SharedConnection<T> - shared connection object
 ///
 /// @return io::Result<()> - result of the operation
-pub fn disconnect(shared_conn: &SharedConnection) -> io::Result<()> {
+pub fn disconnect<T: Transport>(shared_conn: &SharedConnection<T>) -> io::Result<()> {
+    if let Some(stops) = stream_registry().lock().unwrap().remove(&shared_conn_key(shared_conn)) {
+        for stop in stops.iter().filter_map(Weak::upgrade) {
+            stop.store(true, Ordering::Relaxed);
+        }
+    }
+
     let mut conn_lock = lock_connection(shared_conn)?;
     match conn_lock.take() {
         Some(conn) => {
@@ -97,91 +130,813 @@ pub fn disconnect(shared_conn: &SharedConnection) -> io::Result<()> {
     }
 }
 
-/// write data to a serial port
+/// write data to a serial port, retrying up to `retries` times on failure
+/// using the default [`RetryPolicy`] between attempts
 ///
-/// @param shared_conn: &SharedConnection - shared connection object
+/// @param shared_conn: &SharedConnection<T> - shared connection object
+/// @param data: &[u8] - data to write
+///
+/// @return io::Result<usize> - result of the operation
+pub fn write<T: Transport>(
+    shared_conn: &SharedConnection<T>,
+    data: &[u8],
+    retries: usize,
+) -> io::Result<usize> {
+    write_with_retry(shared_conn, data, retries, RetryPolicy::default())
+}
+
+/// write data to a serial port, retrying up to `retries` times on failure
+/// with `policy` controlling the delay between attempts
+///
+/// @param shared_conn: &SharedConnection<T> - shared connection object
 /// @param data: &[u8] - data to write
+/// @param retries: usize - number of retries to attempt on failure
+/// @param policy: RetryPolicy - backoff strategy between attempts
 ///
 /// @return io::Result<usize> - result of the operation
-pub fn write(shared_conn: &SharedConnection, data: &[u8], retries: usize) -> io::Result<usize> {
+pub fn write_with_retry<T: Transport>(
+    shared_conn: &SharedConnection<T>,
+    data: &[u8],
+    retries: usize,
+    policy: RetryPolicy,
+) -> io::Result<usize> {
     // validate input parameters here
     //
     //
 
-    let mut conn_lock = lock_connection(shared_conn)?;
+    let mut attempts: u32 = 0;
 
-    match conn_lock.as_mut() {
-        Some(conn) => {
-            let mut attempts = 0;
-            loop {
-                match conn.write(data) {
-                    Ok(size) => {
-                        info!("[core] wrote {} b", size);
-                        return Ok(size);
-                    }
-                    Err(ref _e) if attempts < retries => {
-                        warn!("[core] write failure #{}", attempts + 1);
-                        attempts += 1;
-                    }
-                    Err(e) => {
-                        error!("[core] write failed after {} attempts: {}", retries, e);
-                        return Err(e);
-                    }
+    loop {
+        // take the lock only for the duration of a single write attempt, not
+        // across the whole retry loop, so a slow backoff doesn't starve a
+        // reader on the other half of a `split()` connection
+        let attempt = {
+            let mut conn_lock = lock_connection(shared_conn)?;
+            match conn_lock.as_mut() {
+                Some(conn) => conn.write(data),
+                None => {
+                    warn!("[core] attempted write on a non-existing connection");
+                    return Err(io::Error::new(
+                        io::ErrorKind::NotConnected,
+                        "[core] no connection",
+                    ));
                 }
             }
-        }
-        None => {
-            warn!("[core] attempted write on a non-existing connection");
-            Err(io::Error::new(
-                io::ErrorKind::NotConnected,
-                "[core] no connection",
-            ))
+        };
+
+        match attempt {
+            Ok(size) => {
+                info!("[core] wrote {} b", size);
+                return Ok(size);
+            }
+            Err(e) if (attempts as usize) < retries => {
+                warn!("[core] write failure #{}: {}", attempts + 1, e);
+                thread::sleep(policy.delay(attempts));
+                attempts += 1;
+            }
+            Err(e) => {
+                error!("[core] write failed after {} attempts: {}", attempts + 1, e);
+                return Err(e);
+            }
         }
     }
 }
 
-/// read data from a serial port
+/// read data from a serial port, polling with the default [`RetryPolicy`]
+/// between attempts until a complete read arrives or `timeout` elapses
 ///
-/// @param shared_conn: &SharedConnection - shared connection object
+/// @param shared_conn: &SharedConnection<T> - shared connection object
 /// @param buffer: &mut [u8] - buffer to read data into
 /// @param timeout: Duration - read timeout
 ///
 /// @return io::Result<usize> - result of the operation
-pub fn read(
-    shared_conn: &SharedConnection,
+pub fn read<T: Transport>(
+    shared_conn: &SharedConnection<T>,
     buffer: &mut [u8],
     timeout: Duration,
 ) -> io::Result<usize> {
-    let mut conn_lock = lock_connection(shared_conn)?;
+    read_with_retry(shared_conn, buffer, timeout, RetryPolicy::default())
+}
 
-    match conn_lock.as_mut() {
-        Some(conn) => {
-            info!("[core] reading data with timeout of {:?}", timeout);
-            let start_time = Instant::now();
-            match conn.read(buffer) {
-                Ok(size) => {
-                    info!("[core] read {} b", size);
-                    Ok(size)
+/// read data from a serial port, retrying with `policy`'s backoff between
+/// poll attempts until the connection's [`ReadMode`](crate::serial::ReadMode) is satisfied or
+/// `timeout` (scaled by the connection's read-timeout multiplier) elapses
+///
+/// a transient error (`WouldBlock`/`TimedOut`/`Interrupted`) is retried like
+/// a plain empty read; any other error is returned immediately
+///
+/// @param shared_conn: &SharedConnection<T> - shared connection object
+/// @param buffer: &mut [u8] - buffer to read data into
+/// @param timeout: Duration - overall read deadline
+/// @param policy: RetryPolicy - backoff strategy between poll attempts
+///
+/// @return io::Result<usize> - result of the operation
+pub fn read_with_retry<T: Transport>(
+    shared_conn: &SharedConnection<T>,
+    buffer: &mut [u8],
+    timeout: Duration,
+    policy: RetryPolicy,
+) -> io::Result<usize> {
+    let (mode, mult) = {
+        let conn_lock = lock_connection(shared_conn)?;
+        match conn_lock.as_ref() {
+            Some(conn) => (conn.read_mode(), conn.read_timeout_mult()),
+            None => {
+                warn!("[core] attempted read on a non-existing connection");
+                return Err(io::Error::new(
+                    io::ErrorKind::NotConnected,
+                    "[core] no connection",
+                ));
+            }
+        }
+    };
+
+    let target = read_target(mode, buffer.len());
+    let deadline = timeout + mult * buffer.len() as u32;
+    info!("[core] reading data with deadline of {:?}", deadline);
+
+    let start_time = Instant::now();
+    let mut attempts: u32 = 0;
+    let mut filled = 0;
+
+    loop {
+        // take the lock only for the duration of a single read attempt, not
+        // across the whole timeout wait, so a long read doesn't starve a
+        // writer on the other half of a `split()` connection
+        let attempt = {
+            let mut conn_lock = lock_connection(shared_conn)?;
+            match conn_lock.as_mut() {
+                Some(conn) => conn.read_once(&mut buffer[filled..]),
+                None => {
+                    warn!("[core] attempted read on a non-existing connection");
+                    return Err(io::Error::new(
+                        io::ErrorKind::NotConnected,
+                        "[core] no connection",
+                    ));
+                }
+            }
+        };
+
+        match attempt {
+            Ok(n) => {
+                filled += n;
+                if filled >= target {
+                    info!("[core] read {} b", filled);
+                    return Ok(filled);
                 }
-                Err(e) if start_time.elapsed() < timeout => {
-                    warn!("[core] read interrupted, retrying...");
+            }
+            Err(e) if is_retryable(e.kind()) => {
+                warn!("[core] read attempt #{} failed, retrying: {}", attempts + 1, e);
+            }
+            Err(e) => {
+                error!("[core] read failed: {}", e);
+                return Err(e);
+            }
+        }
 
-                    // retry logic here
+        if start_time.elapsed() >= deadline {
+            if filled > 0 {
+                warn!(
+                    "[core] read timed out after {:?}, returning partial {} b",
+                    deadline, filled
+                );
+                return Ok(filled);
+            }
+            warn!("[core] read timed out after {:?}", deadline);
+            return Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                "[core] read operation timed out",
+            ));
+        }
 
-                    Err(e)
+        thread::sleep(policy.delay(attempts));
+        attempts += 1;
+    }
+}
+
+/// error kinds worth retrying with backoff rather than surfacing immediately
+fn is_retryable(kind: io::ErrorKind) -> bool {
+    matches!(
+        kind,
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut | io::ErrorKind::Interrupted
+    )
+}
+
+/// one half of a [`split`] connection; holds the same underlying
+/// `SharedConnection` as its [`ConnectionWriter`] counterpart but only ever
+/// takes the lock to read, so it never blocks writes for longer than a
+/// single read attempt
+pub struct ConnectionReader {
+    shared: SharedConnection,
+}
+
+/// the write half of a [`split`] connection, see [`ConnectionReader`]
+pub struct ConnectionWriter {
+    shared: SharedConnection,
+}
+
+/// split a connected `SharedConnection` into independent reader and writer
+/// halves, so a blocking read no longer holds the same mutex a concurrent
+/// write needs, letting a caller drive full-duplex request/response traffic
+/// (e.g. a dedicated reader task alongside writes from the main thread)
+///
+/// @param shared_conn: SharedConnection - shared connection object to split
+///
+/// @return (ConnectionReader, ConnectionWriter) - independent halves over the same connection
+pub fn split(shared_conn: SharedConnection) -> (ConnectionReader, ConnectionWriter) {
+    (
+        ConnectionReader {
+            shared: Arc::clone(&shared_conn),
+        },
+        ConnectionWriter {
+            shared: shared_conn,
+        },
+    )
+}
+
+impl ConnectionReader {
+    /// @return io::Result<usize> - result of the operation, see [`read`]
+    pub fn read(&self, buffer: &mut [u8], timeout: Duration) -> io::Result<usize> {
+        read(&self.shared, buffer, timeout)
+    }
+
+    /// recombine with the [`ConnectionWriter`] half produced by the same
+    /// [`split`] call, handing back the shared connection
+    pub fn reunite(self, writer: ConnectionWriter) -> io::Result<SharedConnection> {
+        if !Arc::ptr_eq(&self.shared, &writer.shared) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "[core] reader and writer do not share the same connection",
+            ));
+        }
+
+        Ok(self.shared)
+    }
+}
+
+impl ConnectionWriter {
+    /// @return io::Result<usize> - result of the operation, see [`write`]
+    pub fn write(&self, data: &[u8], retries: usize) -> io::Result<usize> {
+        write(&self.shared, data, retries)
+    }
+}
+
+/// default chunk size used while accumulating a `read_until_idle` message
+const IDLE_READ_CHUNK: usize = 256;
+
+/// read bytes into `buf` until the line goes quiet for `idle_gap` (no new
+/// byte arrives), returning the number of bytes appended
+///
+/// when `idle_gap` is `None` it defaults to the time it takes to transmit
+/// two byte-frames at the connection's current baud rate (20 bit-periods
+/// for 8N1), derived from `baud_rate`/`data_bits`/`parity`/`stop_bits` so
+/// callers don't have to reason about bit-timing themselves.
+///
+/// @param shared_conn: &SharedConnection - shared connection object
+/// @param buf: &mut Vec<u8> - buffer the accumulated message is appended to
+/// @param idle_gap: Option<Duration> - quiet period that ends the read
+///
+/// @return io::Result<usize> - number of bytes appended to `buf`
+pub fn read_until_idle(
+    shared_conn: &SharedConnection,
+    buf: &mut Vec<u8>,
+    idle_gap: Option<Duration>,
+) -> io::Result<usize> {
+    let gap = match idle_gap {
+        Some(gap) => gap,
+        None => default_idle_gap(shared_conn)?,
+    };
+
+    let mut appended = 0;
+    let mut chunk = vec![0u8; IDLE_READ_CHUNK];
+
+    loop {
+        // poll once per `gap`, so a quiet period of `gap` ends the read
+        // instead of the default policy's longer poll delay
+        match read_with_retry(shared_conn, &mut chunk, gap, RetryPolicy::Fixed { delay: gap }) {
+            Ok(n) if n > 0 => {
+                buf.extend_from_slice(&chunk[..n]);
+                appended += n;
+            }
+            Ok(_) => break,
+            Err(ref e) if e.kind() == io::ErrorKind::TimedOut => break,
+            Err(e) if appended == 0 => return Err(e),
+            Err(_) => break,
+        }
+    }
+
+    Ok(appended)
+}
+
+/// 20 bit-periods at the connection's current baud rate, i.e. two
+/// byte-times for the common 8N1 framing
+fn default_idle_gap(shared_conn: &SharedConnection) -> io::Result<Duration> {
+    let conn_lock = lock_connection(shared_conn)?;
+    let conn = conn_lock
+        .as_ref()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotConnected, "[core] no connection"))?;
+
+    let baud = conn
+        .baud_rate()
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, format!("[core] baud_rate: {}", e)))?;
+    let data_bits = conn
+        .data_bits()
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, format!("[core] data_bits: {}", e)))?;
+    let parity = conn
+        .parity()
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, format!("[core] parity: {}", e)))?;
+    let stop_bits = conn
+        .stop_bits()
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, format!("[core] stop_bits: {}", e)))?;
+
+    let bits_per_byte = bits_per_byte(data_bits, parity, stop_bits);
+    let gap_micros = (2 * bits_per_byte * 1_000_000) / baud as u64;
+    Ok(Duration::from_micros(gap_micros))
+}
+
+/// start bit + data bits + parity bit (if any) + stop bits
+fn bits_per_byte(data_bits: DataBits, parity: Parity, stop_bits: StopBits) -> u64 {
+    let data = match data_bits {
+        DataBits::Five => 5,
+        DataBits::Six => 6,
+        DataBits::Seven => 7,
+        DataBits::Eight => 8,
+    };
+    let parity_bit = match parity {
+        Parity::None => 0,
+        Parity::Odd | Parity::Even => 1,
+    };
+    let stop = match stop_bits {
+        StopBits::One => 1,
+        StopBits::Two => 2,
+    };
+
+    1 + data + parity_bit + stop
+}
+
+/// size of each chunk `read_frame` pulls off the port while it waits for
+/// the parser to report a complete frame
+const READ_FRAME_CHUNK: usize = 256;
+
+/// read from a connection until `parser` yields one complete frame, or
+/// `timeout` elapses without one
+///
+/// unlike plain `read`, the caller doesn't need to know the frame's length
+/// or worry about a response spanning multiple reads: bytes are fed into
+/// `parser`, which retains any partial tail across calls.
+///
+/// @param shared_conn: &SharedConnection - shared connection object
+/// @param parser: &mut Parser - frame parser retaining partial tail bytes across calls
+/// @param timeout: Duration - overall deadline for a complete frame to arrive
+///
+/// @return io::Result<Frame> - the first complete frame parsed out of the stream
+pub fn read_frame(
+    shared_conn: &SharedConnection,
+    parser: &mut crate::parser::Parser,
+    timeout: Duration,
+) -> io::Result<Frame> {
+    // a previous call may have already left a complete frame buffered
+    if let Some(frame) = parser.consume(&[]).next() {
+        return frame.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()));
+    }
+
+    let start_time = Instant::now();
+    let mut chunk = vec![0u8; READ_FRAME_CHUNK];
+
+    while start_time.elapsed() < timeout {
+        let remaining = timeout.saturating_sub(start_time.elapsed());
+        match read(shared_conn, &mut chunk, remaining) {
+            Ok(n) if n > 0 => {
+                if let Some(frame) = parser.consume(&chunk[..n]).next() {
+                    return frame
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()));
+                }
+            }
+            Ok(_) => continue,
+            Err(ref e) if e.kind() == io::ErrorKind::TimedOut => break,
+            Err(e) => return Err(e),
+        }
+    }
+
+    Err(io::Error::new(
+        io::ErrorKind::TimedOut,
+        "[core] read_frame timed out before a complete frame arrived",
+    ))
+}
+
+/// bounded capacity of the channel fed by a `subscribe` reader thread
+const STREAM_CHANNEL_CAPACITY: usize = 64;
+
+/// size of each chunk the `subscribe` reader thread pulls off the port
+const STREAM_READ_CHUNK: usize = 256;
+
+/// sleep between empty reads in the `subscribe` reader thread, so a port
+/// opened with a near-zero native timeout doesn't spin the thread at 100%
+/// CPU while waiting for unsolicited data
+const STREAM_IDLE_SLEEP_MS: u64 = 20;
+
+/// handle for the background reader thread spawned by [`subscribe`]
+///
+/// dropping this (or calling `stop`) signals the reader thread to exit.
+/// [`disconnect`] also signals it: `subscribe` registers this handle's stop
+/// flag against the `SharedConnection` it was created from, so the thread
+/// doesn't keep reading through a port the caller now believes is closed
+/// even if the handle itself is still held.
+pub struct StreamHandle {
+    stop: Arc<AtomicBool>,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl StreamHandle {
+    /// signal the reader thread to stop and wait for it to exit
+    pub fn stop(mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for StreamHandle {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// subscribe to unsolicited data from a connection
+///
+/// spawns a dedicated reader thread that pulls from the underlying port and
+/// pushes received bytes onto a bounded channel, so a consumer can pull
+/// chunks as they arrive via the returned [`Receiver`] instead of polling
+/// `read` itself.
+///
+/// @param shared_conn: &SharedConnection - shared connection object
+///
+/// @return io::Result<(Receiver<Vec<u8>>, StreamHandle)> - the receiving
+/// end of the stream and a handle controlling the reader thread's lifecycle
+pub fn subscribe(shared_conn: &SharedConnection) -> io::Result<(Receiver<Vec<u8>>, StreamHandle)> {
+    let mut port = {
+        let conn_lock = lock_connection(shared_conn)?;
+        match conn_lock.as_ref() {
+            Some(conn) => conn.try_clone().map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::Other,
+                    format!("[core] failed to clone port for streaming: {}", e),
+                )
+            })?,
+            None => {
+                warn!("[core] attempted subscribe on a non-existing connection");
+                return Err(io::Error::new(
+                    io::ErrorKind::NotConnected,
+                    "[core] no connection",
+                ));
+            }
+        }
+    };
+
+    let (tx, rx): (SyncSender<Vec<u8>>, Receiver<Vec<u8>>) =
+        mpsc::sync_channel(STREAM_CHANNEL_CAPACITY);
+    let stop = Arc::new(AtomicBool::new(false));
+    let stop_flag = Arc::clone(&stop);
+
+    {
+        let mut registry = stream_registry().lock().unwrap();
+        let stops = registry.entry(shared_conn_key(shared_conn)).or_default();
+        // drop entries for handles that already stopped without a
+        // disconnect() ever coming along to reap them
+        stops.retain(|w| w.strong_count() > 0);
+        stops.push(Arc::downgrade(&stop));
+    }
+
+    info!("[core] starting background reader thread");
+    let handle = thread::spawn(move || {
+        let mut chunk = vec![0u8; STREAM_READ_CHUNK];
+        while !stop_flag.load(Ordering::Relaxed) {
+            match port.read(&mut chunk) {
+                Ok(0) => thread::sleep(Duration::from_millis(STREAM_IDLE_SLEEP_MS)),
+                Ok(n) => {
+                    if tx.send(chunk[..n].to_vec()).is_err() {
+                        // receiver dropped, nothing left to stream to
+                        break;
+                    }
+                }
+                Err(ref e) if e.kind() == io::ErrorKind::TimedOut => {
+                    thread::sleep(Duration::from_millis(STREAM_IDLE_SLEEP_MS))
                 }
                 Err(e) => {
-                    error!("[core] read failed after timeout: {}", e);
+                    error!("[core] background reader exiting: {}", e);
+                    break;
+                }
+            }
+        }
+    });
+
+    Ok((
+        rx,
+        StreamHandle {
+            stop,
+            handle: Some(handle),
+        },
+    ))
+}
+
+/// connect to a serial port using a compact descriptor string (e.g.
+/// `"/dev/ttyUSB0:9600,8N1,none"`) instead of a pre-built `SerialPortBuilder`
+///
+/// @param shared_conn: &SharedConnection - shared connection object
+/// @param descriptor: &str - compact connection descriptor, see `serial_types::parse_descriptor`
+///
+/// @return io::Result<()> - result of the operation
+pub fn connect_from_descriptor(shared_conn: &SharedConnection, descriptor: &str) -> io::Result<()> {
+    let builder = crate::serial_types::parse_descriptor(descriptor.to_string()).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("[core] invalid connection descriptor: {}", e),
+        )
+    })?;
+
+    connect(shared_conn, builder)
+}
+
+/// async mirror of `connect`/`disconnect`/`read`/`write`, backed by the
+/// reactor-driven `serial::async::AsyncSerialConnection` instead of the
+/// blocking `SerialConnection`
+///
+/// kept in its own sub-module so callers that don't need tokio can ignore
+/// it entirely behind the `async` feature. the reactor-driven functions
+/// below are unix-only (`serial::async` is only compiled on unix); the
+/// `blocking` sub-module underneath is not and stays
+/// available on every target `serialport` itself supports.
+#[cfg(feature = "async")]
+pub mod r#async {
+    #[cfg(unix)]
+    use crate::serial::r#async::AsyncSerialConnection;
+    #[cfg(unix)]
+    use serialport::SerialPortBuilder;
+    #[cfg(unix)]
+    use std::io;
+    #[cfg(unix)]
+    use std::sync::Arc;
+    #[cfg(unix)]
+    use std::time::Duration;
+    #[cfg(unix)]
+    use tokio::sync::Mutex;
+
+    #[cfg(unix)]
+    use log::{error, info, warn};
+
+    /// async counterpart to [`super::SharedConnection`]
+    #[cfg(unix)]
+    pub type AsyncSharedConnection = Arc<Mutex<Option<AsyncSerialConnection>>>;
+
+    /// connect to a serial port without blocking the calling task
+    ///
+    /// @param shared_conn: &AsyncSharedConnection - shared connection object
+    /// @param port: SerialPortBuilder - serial port builder object
+    ///
+    /// @return io::Result<()> - result of the operation
+    #[cfg(unix)]
+    pub async fn connect(shared_conn: &AsyncSharedConnection, port: SerialPortBuilder) -> io::Result<()> {
+        info!("[core/async] connecting to {:?}", port);
+
+        let conn = AsyncSerialConnection::connect(port).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::ConnectionRefused,
+                format!("[core/async] connection refused: {}", e),
+            )
+        })?;
+
+        let mut conn_lock = shared_conn.lock().await;
+        *conn_lock = Some(conn);
+        info!("[core/async] connected");
+        Ok(())
+    }
+
+    /// disconnect from a serial port
+    ///
+    /// @param shared_conn: &AsyncSharedConnection - shared connection object
+    ///
+    /// @return io::Result<()> - result of the operation
+    #[cfg(unix)]
+    pub async fn disconnect(shared_conn: &AsyncSharedConnection) -> io::Result<()> {
+        let mut conn_lock = shared_conn.lock().await;
+        match conn_lock.take() {
+            Some(conn) => {
+                info!("[core/async] disconnecting");
+                conn.disconnect()
+            }
+            None => {
+                warn!("[core/async] lock not obtained (likely not connected)");
+                Err(io::Error::new(
+                    io::ErrorKind::NotConnected,
+                    "[core/async] no connection",
+                ))
+            }
+        }
+    }
+
+    /// read data from a serial port, yielding the task instead of blocking
+    /// while waiting for bytes or honoring the timeout
+    ///
+    /// @param shared_conn: &AsyncSharedConnection - shared connection object
+    /// @param buffer: &mut [u8] - buffer to read data into
+    /// @param timeout: Duration - read timeout
+    ///
+    /// @return io::Result<usize> - result of the operation
+    #[cfg(unix)]
+    pub async fn read(
+        shared_conn: &AsyncSharedConnection,
+        buffer: &mut [u8],
+        timeout: Duration,
+    ) -> io::Result<usize> {
+        let mut conn_lock = shared_conn.lock().await;
+
+        match conn_lock.as_mut() {
+            Some(conn) => {
+                info!("[core/async] reading data with timeout of {:?}", timeout);
+                match conn.read(buffer, timeout).await {
+                    Ok(size) => {
+                        info!("[core/async] read {} b", size);
+                        Ok(size)
+                    }
+                    Err(e) => {
+                        error!("[core/async] read failed: {}", e);
+                        Err(e)
+                    }
+                }
+            }
+            None => {
+                warn!("[core/async] attempted read on a non-existing connection");
+                Err(io::Error::new(
+                    io::ErrorKind::NotConnected,
+                    "[core/async] no connection",
+                ))
+            }
+        }
+    }
+
+    /// write data to a serial port
+    ///
+    /// @param shared_conn: &AsyncSharedConnection - shared connection object
+    /// @param data: &[u8] - data to write
+    ///
+    /// @return io::Result<usize> - result of the operation
+    #[cfg(unix)]
+    pub async fn write(shared_conn: &AsyncSharedConnection, data: &[u8]) -> io::Result<usize> {
+        let mut conn_lock = shared_conn.lock().await;
+
+        match conn_lock.as_mut() {
+            Some(conn) => match conn.write(data).await {
+                Ok(size) => {
+                    info!("[core/async] wrote {} b", size);
+                    Ok(size)
+                }
+                Err(e) => {
+                    error!("[core/async] write failed: {}", e);
                     Err(e)
                 }
+            },
+            None => {
+                warn!("[core/async] attempted write on a non-existing connection");
+                Err(io::Error::new(
+                    io::ErrorKind::NotConnected,
+                    "[core/async] no connection",
+                ))
             }
         }
-        None => {
-            warn!("[core] attempted read on a non-existing connection");
-            Err(io::Error::new(
-                io::ErrorKind::NotConnected,
-                "[core] no connection",
-            ))
+    }
+
+    /// spawn_blocking-backed async mirror of the core functions
+    ///
+    /// the reactor-driven functions above need the port's raw fd, which
+    /// restricts them to unix. this variant instead offloads each blocking
+    /// `SharedConnection` call onto tokio's blocking thread pool, so the
+    /// calling task still yields instead of blocking while waiting for
+    /// bytes, on any platform `serialport` itself supports.
+    pub mod blocking {
+        use crate::api::{self, SharedConnection};
+        use serialport::SerialPortBuilder;
+        use std::io;
+        use std::time::Duration;
+
+        fn join_err(e: tokio::task::JoinError) -> io::Error {
+            io::Error::new(
+                io::ErrorKind::Other,
+                format!("[core/async] blocking task panicked: {}", e),
+            )
+        }
+
+        pub async fn connect(shared_conn: SharedConnection, port: SerialPortBuilder) -> io::Result<()> {
+            tokio::task::spawn_blocking(move || api::connect(&shared_conn, port))
+                .await
+                .map_err(join_err)?
+        }
+
+        pub async fn disconnect(shared_conn: SharedConnection) -> io::Result<()> {
+            tokio::task::spawn_blocking(move || api::disconnect(&shared_conn))
+                .await
+                .map_err(join_err)?
+        }
+
+        /// @return io::Result<(usize, Vec<u8>)> - bytes read, and the buffer
+        /// handed back since it has to move through the blocking task
+        pub async fn read(
+            shared_conn: SharedConnection,
+            mut buffer: Vec<u8>,
+            timeout: Duration,
+        ) -> io::Result<(usize, Vec<u8>)> {
+            // the blocking task already enforces `timeout` itself via
+            // `api::read`; an outer `tokio::time::timeout` on top of it would
+            // race the same deadline against scheduling delay rather than
+            // add real cancellation (a spawned blocking task can't be
+            // cancelled once running, so it'd keep holding the connection's
+            // mutex after a "timed out" caller moved on)
+            tokio::task::spawn_blocking(move || {
+                let size = api::read(&shared_conn, &mut buffer, timeout)?;
+                Ok::<_, io::Error>((size, buffer))
+            })
+            .await
+            .map_err(join_err)?
         }
+
+        pub async fn write(
+            shared_conn: SharedConnection,
+            data: Vec<u8>,
+            retries: usize,
+        ) -> io::Result<usize> {
+            tokio::task::spawn_blocking(move || api::write(&shared_conn, &data, retries))
+                .await
+                .map_err(join_err)?
+        }
+    }
+}
+
+#[cfg(all(test, feature = "test-util"))]
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+    use std::sync::{Arc, Mutex};
+
+    fn shared(mock: MockTransport) -> SharedConnection<MockTransport> {
+        Arc::new(Mutex::new(Some(mock)))
+    }
+
+    #[test]
+    fn write_with_retry_succeeds_after_a_transient_failure() {
+        let mut mock = MockTransport::new();
+        mock.fail_write_on(1, io::ErrorKind::TimedOut);
+        let shared_conn = shared(mock);
+
+        let result = write_with_retry(&shared_conn, b"ping", 3, RetryPolicy::default());
+
+        assert_eq!(result.unwrap(), 4);
+        let conn_lock = shared_conn.lock().unwrap();
+        let mock = conn_lock.as_ref().unwrap();
+        assert_eq!(mock.written, b"ping");
+    }
+
+    #[test]
+    fn write_with_retry_gives_up_after_retries_exhausted() {
+        let mut mock = MockTransport::new();
+        mock.fail_write_on(1, io::ErrorKind::TimedOut);
+        let shared_conn = shared(mock);
+
+        let result = write_with_retry(&shared_conn, b"ping", 0, RetryPolicy::default());
+
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::TimedOut);
+    }
+
+    #[test]
+    fn read_with_retry_times_out_when_nothing_arrives() {
+        let shared_conn = shared(MockTransport::new());
+        let mut buf = [0u8; 8];
+
+        let result = read_with_retry(
+            &shared_conn,
+            &mut buf,
+            Duration::from_millis(20),
+            RetryPolicy::default(),
+        );
+
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::TimedOut);
+    }
+
+    #[test]
+    fn read_with_retry_retries_past_a_transient_error() {
+        let mut mock = MockTransport::new();
+        mock.fail_read_on(1, io::ErrorKind::WouldBlock);
+        mock.push_read(b"ok".to_vec());
+        let shared_conn = shared(mock);
+        let mut buf = [0u8; 8];
+
+        let result = read_with_retry(
+            &shared_conn,
+            &mut buf,
+            Duration::from_secs(1),
+            RetryPolicy::default(),
+        );
+
+        assert_eq!(result.unwrap(), 2);
+        assert_eq!(&buf[..2], b"ok");
     }
 }
@@ -0,0 +1,122 @@
+// -- serial-over-TCP bridge
+// binds a TCP listener and pipes a connected SharedConnection bidirectionally
+// to accepted sockets (a ser2net-style gateway), so a remote client can drive
+// a local serial device over the network without a direct connection to it
+
+use crate::api::{self, SharedConnection};
+use crate::retry::RetryPolicy;
+use log::{error, info, warn};
+use std::io::{self, Read, Write};
+use std::net::{Shutdown, TcpListener, TcpStream, ToSocketAddrs};
+use std::thread;
+use std::time::Duration;
+
+/// chunk size used while forwarding bytes between the socket and the serial side
+const BRIDGE_CHUNK: usize = 256;
+
+/// bind `addr` and forward every accepted connection bidirectionally to
+/// `shared_conn`: socket reads are written to the serial side, and serial
+/// reads are written back to the socket, honoring the same
+/// retry/timeout policy as the core `read`/`write` functions. blocks the
+/// calling thread accepting connections — run it on its own thread to
+/// keep it in the background.
+///
+/// @param shared_conn: SharedConnection - an already-connected shared connection
+/// @param addr: impl ToSocketAddrs - address to bind the TCP listener on
+/// @param timeout: Duration - read timeout passed through to the serial side
+/// @param retries: usize - write retry count passed through to the serial side
+/// @param policy: RetryPolicy - backoff strategy shared with the serial side
+///
+/// @return io::Result<()> - result of binding the listener (per-connection
+/// forwarding errors are logged and only tear down that connection)
+pub fn serve(
+    shared_conn: SharedConnection,
+    addr: impl ToSocketAddrs,
+    timeout: Duration,
+    retries: usize,
+    policy: RetryPolicy,
+) -> io::Result<()> {
+    let listener = TcpListener::bind(addr)?;
+    info!("[bridge] listening on {:?}", listener.local_addr());
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(socket) => {
+                let conn = shared_conn.clone();
+                thread::spawn(move || bridge_connection(conn, socket, timeout, retries, policy));
+            }
+            Err(e) => {
+                warn!("[bridge] failed to accept connection: {}", e);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// forward one accepted socket until either side hangs up, then tear down
+/// both directions cleanly
+fn bridge_connection(
+    shared_conn: SharedConnection,
+    socket: TcpStream,
+    timeout: Duration,
+    retries: usize,
+    policy: RetryPolicy,
+) {
+    let peer = socket.peer_addr().ok();
+    info!("[bridge] client connected: {:?}", peer);
+
+    let mut socket_reader = match socket.try_clone() {
+        Ok(s) => s,
+        Err(e) => {
+            error!("[bridge] failed to clone socket for {:?}: {}", peer, e);
+            return;
+        }
+    };
+    let mut socket_writer = socket;
+
+    // socket -> serial
+    let write_conn = shared_conn.clone();
+    let inbound = thread::spawn(move || {
+        let mut chunk = vec![0u8; BRIDGE_CHUNK];
+        loop {
+            match socket_reader.read(&mut chunk) {
+                Ok(0) => break, // client hung up
+                Ok(n) => {
+                    if let Err(e) =
+                        api::write_with_retry(&write_conn, &chunk[..n], retries, policy)
+                    {
+                        error!("[bridge] serial write failed: {}", e);
+                        break;
+                    }
+                }
+                Err(e) => {
+                    warn!("[bridge] socket read failed: {}", e);
+                    break;
+                }
+            }
+        }
+    });
+
+    // serial -> socket
+    let mut chunk = vec![0u8; BRIDGE_CHUNK];
+    while !inbound.is_finished() {
+        match api::read_with_retry(&shared_conn, &mut chunk, timeout, policy) {
+            Ok(n) if n > 0 => {
+                if socket_writer.write_all(&chunk[..n]).is_err() {
+                    break;
+                }
+            }
+            Ok(_) => {}
+            Err(ref e) if e.kind() == io::ErrorKind::TimedOut => continue,
+            Err(e) => {
+                warn!("[bridge] serial read failed: {}", e);
+                break;
+            }
+        }
+    }
+
+    let _ = socket_writer.shutdown(Shutdown::Both);
+    let _ = inbound.join();
+    info!("[bridge] client disconnected: {:?}", peer);
+}
@@ -0,0 +1,49 @@
+// -- delimiter-framed request/response layer
+// wraps a SharedConnection so callers work in whole messages instead of
+// hand-rolling boundaries on raw read/write buffers
+
+use crate::api::{self, SharedConnection};
+use crate::parser::{Frame, FrameFormat, Parser};
+use std::io;
+use std::time::Duration;
+
+/// wraps a [`SharedConnection`] and reassembles delimiter-framed messages
+/// across reads. internally this is just a [`Parser`] configured for
+/// `FrameFormat::Delimited`, so partial frames are retained across calls
+/// and multiple frames arriving in one read are queued rather than lost.
+pub struct FramedConnection {
+    shared: SharedConnection,
+    parser: Parser,
+    delimiter: Vec<u8>,
+}
+
+impl FramedConnection {
+    /// frame on a single delimiter byte, defaulting to `b'\n'`
+    pub fn new(shared: SharedConnection) -> Self {
+        Self::with_delimiter(shared, b"\n".to_vec())
+    }
+
+    pub fn with_delimiter(shared: SharedConnection, delimiter: Vec<u8>) -> Self {
+        let parser = Parser::new(FrameFormat::Delimited(delimiter.clone()));
+        Self {
+            shared,
+            parser,
+            delimiter,
+        }
+    }
+
+    /// return exactly one complete message, buffering any partial tail
+    /// across calls
+    pub fn next_frame(&mut self, timeout: Duration) -> io::Result<Vec<u8>> {
+        api::read_frame(&self.shared, &mut self.parser, timeout).map(|Frame(bytes)| bytes)
+    }
+
+    /// write `data` followed by the delimiter, then block for the next
+    /// response frame within `timeout`
+    pub fn request(&mut self, data: &[u8], timeout: Duration) -> io::Result<Vec<u8>> {
+        let mut line = data.to_vec();
+        line.extend_from_slice(&self.delimiter);
+        api::write(&self.shared, &line, 0)?;
+        self.next_frame(timeout)
+    }
+}
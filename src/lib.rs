@@ -2,6 +2,23 @@
 // exposes the API to external code
 
 pub mod api;
+#[cfg(feature = "net")]
+pub mod bridge;
+pub mod framed;
+pub mod parser;
+pub mod retry;
 pub mod serial;
+pub mod serial_types;
+pub mod transport;
 
-pub use api::{connect, disconnect, list, read, write};
+pub use api::{
+    connect, connect_from_descriptor, disconnect, list, read, read_frame, read_until_idle,
+    read_with_retry, split, subscribe, write, write_with_retry, ConnectionReader,
+    ConnectionWriter, StreamHandle,
+};
+pub use framed::FramedConnection;
+pub use parser::{Frame, FrameFormat, ParseError, Parser};
+pub use retry::RetryPolicy;
+pub use transport::Transport;
+#[cfg(feature = "test-util")]
+pub use transport::MockTransport;
@@ -0,0 +1,43 @@
+// -- configurable retry/backoff policy shared by read and write
+
+use std::time::Duration;
+
+/// backoff strategy used between retry attempts in [`crate::api::read_with_retry`]
+/// and [`crate::api::write_with_retry`]
+#[derive(Clone, Copy, Debug)]
+pub enum RetryPolicy {
+    /// wait the same `delay` between every attempt
+    Fixed { delay: Duration },
+    /// start at `base_delay` and multiply by `multiplier` after each
+    /// attempt, capped at `max_delay`
+    Exponential {
+        base_delay: Duration,
+        multiplier: f64,
+        max_delay: Duration,
+    },
+}
+
+impl RetryPolicy {
+    /// the delay to wait before the `attempt`'th (0-indexed) retry
+    pub fn delay(&self, attempt: u32) -> Duration {
+        match *self {
+            RetryPolicy::Fixed { delay } => delay,
+            RetryPolicy::Exponential {
+                base_delay,
+                multiplier,
+                max_delay,
+            } => {
+                let scaled = base_delay.as_secs_f64() * multiplier.powi(attempt as i32);
+                Duration::from_secs_f64(scaled.clamp(0.0, max_delay.as_secs_f64()))
+            }
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::Fixed {
+            delay: Duration::from_millis(20),
+        }
+    }
+}
@@ -10,13 +10,38 @@ use std::time::{Duration, Instant};
 /// polling read period for the duration of a user-defined timeout
 const READ_POLL_SLEEP_MS: u64 = 100;
 
+/// read semantics controlling how `Read::read` behaves on [`SerialConnection`]
+#[derive(Clone, Copy, Debug)]
+pub enum ReadMode {
+    /// return as soon as any bytes are available (default behavior)
+    Any,
+    /// accumulate until `min_bytes` (or `buf.len()` if smaller) are
+    /// collected, or the deadline elapses — returning a partial count only
+    /// on timeout
+    AllOrNothing { min_bytes: usize },
+}
+
+impl Default for ReadMode {
+    fn default() -> Self {
+        ReadMode::Any
+    }
+}
+
 pub struct SerialConnection {
     port: Box<dyn SerialPort>,
+    mode: ReadMode,
+    /// scales the effective read deadline by the requested buffer length:
+    /// total budget = `self.timeout() + buf.len() * read_timeout_mult`
+    read_timeout_mult: Duration,
 }
 
 impl SerialConnection {
     pub fn new(port: Box<dyn SerialPort>) -> Self {
-        SerialConnection { port }
+        SerialConnection {
+            port,
+            mode: ReadMode::default(),
+            read_timeout_mult: Duration::ZERO,
+        }
     }
 
     pub fn list() -> io::Result<Vec<SerialPortInfo>> {
@@ -30,13 +55,38 @@ impl SerialConnection {
         // flush to ensure buffer emptiness before writing (TODO: error handling)
         port.flush()?;
 
-        Ok(Self { port })
+        Ok(Self {
+            port,
+            mode: ReadMode::default(),
+            read_timeout_mult: Duration::ZERO,
+        })
     }
 
     pub fn disconnect(self) -> io::Result<()> {
         drop(self.port);
         Ok(())
     }
+
+    /// select `AllOrNothing`/`Any` read semantics for subsequent `read` calls
+    pub fn set_read_mode(&mut self, mode: ReadMode) {
+        self.mode = mode;
+    }
+
+    /// set the per-byte timeout multiplier used to scale the read deadline
+    /// by the requested buffer length
+    pub fn set_read_timeout_mult(&mut self, mult: Duration) {
+        self.read_timeout_mult = mult;
+    }
+
+    /// read semantics set via [`Self::set_read_mode`]
+    pub fn read_mode(&self) -> ReadMode {
+        self.mode
+    }
+
+    /// per-byte timeout multiplier set via [`Self::set_read_timeout_mult`]
+    pub fn read_timeout_mult(&self) -> Duration {
+        self.read_timeout_mult
+    }
 }
 
 /// serial port driver implementation
@@ -142,47 +192,93 @@ impl SerialPort for SerialConnection {
     }
 }
 
+/// maps a `serialport::Error` onto the `io::ErrorKind` it wraps, falling
+/// back to `Other` for the variants (`NoDevice`, `InvalidInput`, `Unknown`)
+/// that have no `io::ErrorKind` equivalent
+fn serialport_io_kind(e: &serialport::Error) -> io::ErrorKind {
+    match e.kind() {
+        serialport::ErrorKind::Io(kind) => kind,
+        _ => io::ErrorKind::Other,
+    }
+}
+
+impl SerialConnection {
+    /// a single read attempt: checks `bytes_to_read` once and, if any are
+    /// buffered, performs one `read` syscall — returning `Ok(0)` rather
+    /// than blocking or sleeping if nothing was available yet. this lets a
+    /// caller holding a shared lock (e.g. `api::read`) release it between
+    /// attempts instead of blocking other lock holders for a whole timeout.
+    pub fn read_once(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.port.bytes_to_read() {
+            Ok(bytes) if bytes > 0 => match self.port.read(buf) {
+                Ok(n) => Ok(n),
+                // already a real io::Error (from the `Read` syscall), so
+                // keep its kind instead of collapsing it to `Other` --
+                // callers like `read_with_retry` retry on `WouldBlock`/
+                // `TimedOut`/`Interrupted`
+                Err(e) => Err(io::Error::new(
+                    e.kind(),
+                    format!("[core] error reading bytes>: {}", e),
+                )),
+            },
+            Ok(_) => Ok(0),
+            Err(e) => Err(io::Error::new(
+                serialport_io_kind(&e),
+                format!("[core] error reading number of bytes to read>: {}", e),
+            )),
+        }
+    }
+
+    /// total read budget for a call against `buf`: the port's configured
+    /// timeout plus `buf.len() * read_timeout_mult`
+    fn read_deadline(&self, buf_len: usize) -> Duration {
+        self.timeout() + self.read_timeout_mult * buf_len as u32
+    }
+}
+
+/// how many bytes a read call should accumulate before returning, given
+/// `mode` and the caller's buffer length — shared by [`Read::read`] below
+/// and [`crate::api::read_with_retry`] so ReadMode has a single definition
+/// of "done" instead of two that can silently diverge
+pub(crate) fn read_target(mode: ReadMode, buf_len: usize) -> usize {
+    match mode {
+        ReadMode::Any => 1.min(buf_len),
+        ReadMode::AllOrNothing { min_bytes } => min_bytes.min(buf_len),
+    }
+}
+
 impl Read for SerialConnection {
+    /// accumulates via [`Self::read_once`] on a [`READ_POLL_SLEEP_MS`] poll
+    /// interval until `read_target` bytes are collected or
+    /// [`Self::read_deadline`] elapses, returning a partial count on
+    /// timeout rather than an error if anything was collected at all. this
+    /// is the same ReadMode/timeout handling `api::read_with_retry` applies
+    /// over the `Transport` trait, for callers driving a `SerialConnection`
+    /// directly instead of through a locked `SharedConnection`.
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let target = read_target(self.mode, buf.len());
+        let deadline = self.read_deadline(buf.len());
         let start_time = Instant::now();
+        let mut filled = 0;
 
-        while start_time.elapsed() < self.timeout() {
-            match self.port.bytes_to_read() {
-                Ok(bytes) => {
-                    if bytes > 0 {
-                        let _ = match self.port.read(buf) {
-                            Ok(bytes_read) => {
-                                if bytes_read > 0 {
-                                    return Ok(bytes_read);
-                                }
-                            }
-
-                            Err(e) => {
-                                return Err(io::Error::new(
-                                    io::ErrorKind::Other,
-                                    format!("[core] error reading bytes>: {}", e),
-                                ));
-                            }
-                        };
-                    }
-                }
+        loop {
+            filled += self.read_once(&mut buf[filled..])?;
+            if filled >= target {
+                return Ok(filled);
+            }
 
-                Err(e) => {
-                    return Err(io::Error::new(
-                        io::ErrorKind::Other,
-                        format!("[core] error reading number of bytes to read>: {}", e),
-                    ));
+            if start_time.elapsed() >= deadline {
+                if filled > 0 {
+                    return Ok(filled);
                 }
+                return Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "[core] read operation timed out",
+                ));
             }
 
             thread::sleep(Duration::from_millis(READ_POLL_SLEEP_MS));
         }
-
-        // read timeout elapsed
-        Err(io::Error::new(
-            io::ErrorKind::TimedOut,
-            "Read operation timed out",
-        ))
     }
 }
 
@@ -195,3 +291,85 @@ impl Write for SerialConnection {
         self.port.flush()
     }
 }
+
+/// reactor-backed counterpart to [`SerialConnection`]
+///
+/// registers the port's raw fd with tokio's reactor (the same approach
+/// tokio-serial takes), so a read future resolves as soon as the OS reports
+/// readable data instead of polling `bytes_to_read` on a sleep loop. unix
+/// only: tokio's `AsyncFd` has no Windows HANDLE equivalent, so on other
+/// targets this module is not compiled and `api::async` offers only its
+/// `blocking` sub-module.
+#[cfg(all(feature = "async", unix))]
+pub mod r#async {
+    use super::SerialConnection;
+    use serialport::SerialPortBuilder;
+    use std::io::{self, Write};
+    use std::os::unix::io::{AsRawFd, RawFd};
+    use std::time::Duration;
+    use tokio::io::unix::AsyncFd;
+
+    impl AsRawFd for SerialConnection {
+        fn as_raw_fd(&self) -> RawFd {
+            // native serial handles on unix (TTYPort) are backed by a fd;
+            // `serialport` exposes it through `SerialPort::try_clone`'s
+            // underlying handle, mirrored here via the native accessor.
+            self.port.as_raw_fd()
+        }
+    }
+
+    pub struct AsyncSerialConnection {
+        inner: AsyncFd<SerialConnection>,
+    }
+
+    impl AsyncSerialConnection {
+        /// open `spbuild` via `SerialConnection::connect` and register it
+        /// with the reactor; the fd is not switched to non-blocking mode
+        pub fn connect(spbuild: SerialPortBuilder) -> io::Result<Self> {
+            let conn = SerialConnection::connect(spbuild)?;
+            Ok(Self {
+                inner: AsyncFd::new(conn)?,
+            })
+        }
+
+        pub fn disconnect(self) -> io::Result<()> {
+            self.inner.into_inner().disconnect()
+        }
+
+        /// resolves as soon as the reactor reports readable data, or after
+        /// `timeout` elapses — each readiness notification is followed by a
+        /// single non-blocking attempt via `read_once`, not the blocking
+        /// `Read` impl, so the tokio worker thread is never parked in a
+        /// sleep-and-retry loop waiting on it
+        pub async fn read(&mut self, buf: &mut [u8], timeout: Duration) -> io::Result<usize> {
+            tokio::time::timeout(timeout, async {
+                loop {
+                    let mut guard = self.inner.readable_mut().await?;
+                    let result = guard.try_io(|inner| match inner.get_mut().read_once(buf) {
+                        Ok(0) => Err(io::Error::new(
+                            io::ErrorKind::WouldBlock,
+                            "no data available yet",
+                        )),
+                        other => other,
+                    });
+                    match result {
+                        Ok(result) => return result,
+                        Err(_would_block) => continue,
+                    }
+                }
+            })
+            .await
+            .unwrap_or_else(|_| Err(io::Error::new(io::ErrorKind::TimedOut, "async read timed out")))
+        }
+
+        pub async fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            loop {
+                let mut guard = self.inner.writable_mut().await?;
+                match guard.try_io(|inner| inner.get_mut().write(buf)) {
+                    Ok(result) => return result,
+                    Err(_would_block) => continue,
+                }
+            }
+        }
+    }
+}
@@ -131,4 +131,150 @@ impl SerialPortInfo {
             }
         };
     }
+
+    /// the human-writable counterpart to `from_json`/`to_json`: a compact
+    /// descriptor string like `"/dev/ttyUSB0:9600,8N1,none"`, suitable for
+    /// config files or CLI args
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn to_descriptor(&self) -> String {
+        let data_bits = match self.data_bits {
+            DataBits::Five => '5',
+            DataBits::Six => '6',
+            DataBits::Seven => '7',
+            DataBits::Eight => '8',
+        };
+        let parity = match self.parity {
+            Parity::None => 'N',
+            Parity::Odd => 'O',
+            Parity::Even => 'E',
+        };
+        let stop_bits = match self.stop_bits {
+            StopBits::One => '1',
+            StopBits::Two => '2',
+        };
+        let flow_control = match self.flow_control {
+            FlowControl::None => "none",
+            FlowControl::Software => "software",
+            FlowControl::Hardware => "hardware",
+        };
+
+        format!(
+            "{}:{},{}{}{},{}",
+            self.name, self.speed, data_bits, parity, stop_bits, flow_control
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum DescriptorError {
+    Malformed(String),
+    InvalidBaudRate(String),
+    InvalidDataBits(char),
+    InvalidParity(char),
+    InvalidStopBits(char),
+    InvalidFlowControl(String),
+}
+
+impl std::fmt::Display for DescriptorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DescriptorError::Malformed(s) => write!(f, "malformed descriptor: {:?}", s),
+            DescriptorError::InvalidBaudRate(s) => write!(f, "invalid baud rate: {:?}", s),
+            DescriptorError::InvalidDataBits(c) => write!(f, "invalid data bits: {:?}", c),
+            DescriptorError::InvalidParity(c) => write!(f, "invalid parity: {:?}", c),
+            DescriptorError::InvalidStopBits(c) => write!(f, "invalid stop bits: {:?}", c),
+            DescriptorError::InvalidFlowControl(s) => write!(f, "invalid flow control: {:?}", s),
+        }
+    }
+}
+
+impl std::error::Error for DescriptorError {}
+
+/// parse a compact descriptor like `"/dev/ttyUSB0:9600,8N1,none"` (path,
+/// baud, data-bits/parity/stop-bits shorthand, flow control) into a
+/// `SerialPortBuilder`, complementing the `from_json`/`to_json` helpers
+/// with a form suitable for config files, CLI args, or passing several
+/// port specs at once
+#[flutter_rust_bridge::frb(sync)]
+pub fn parse_descriptor(
+    descriptor: String,
+) -> Result<serialport::SerialPortBuilder, DescriptorError> {
+    let (path, rest) = descriptor
+        .split_once(':')
+        .ok_or_else(|| DescriptorError::Malformed(descriptor.clone()))?;
+
+    let mut fields = rest.split(',');
+
+    let baud: u32 = fields
+        .next()
+        .ok_or_else(|| DescriptorError::Malformed(descriptor.clone()))?
+        .parse()
+        .map_err(|_| DescriptorError::InvalidBaudRate(rest.to_string()))?;
+
+    let shorthand = fields
+        .next()
+        .ok_or_else(|| DescriptorError::Malformed(descriptor.clone()))?;
+    let mut shorthand_chars = shorthand.chars();
+    let data_bits = parse_data_bits(
+        shorthand_chars
+            .next()
+            .ok_or_else(|| DescriptorError::Malformed(descriptor.clone()))?,
+    )?;
+    let parity = parse_parity(
+        shorthand_chars
+            .next()
+            .ok_or_else(|| DescriptorError::Malformed(descriptor.clone()))?,
+    )?;
+    let stop_bits = parse_stop_bits(
+        shorthand_chars
+            .next()
+            .ok_or_else(|| DescriptorError::Malformed(descriptor.clone()))?,
+    )?;
+
+    let flow_control = match fields.next() {
+        Some(flow) => parse_flow_control(flow)?,
+        None => serialport::FlowControl::None,
+    };
+
+    Ok(serialport::new(path, baud)
+        .data_bits(data_bits)
+        .parity(parity)
+        .stop_bits(stop_bits)
+        .flow_control(flow_control))
+}
+
+fn parse_data_bits(c: char) -> Result<serialport::DataBits, DescriptorError> {
+    match c {
+        '5' => Ok(serialport::DataBits::Five),
+        '6' => Ok(serialport::DataBits::Six),
+        '7' => Ok(serialport::DataBits::Seven),
+        '8' => Ok(serialport::DataBits::Eight),
+        other => Err(DescriptorError::InvalidDataBits(other)),
+    }
+}
+
+fn parse_parity(c: char) -> Result<serialport::Parity, DescriptorError> {
+    match c.to_ascii_uppercase() {
+        'N' => Ok(serialport::Parity::None),
+        'O' => Ok(serialport::Parity::Odd),
+        'E' => Ok(serialport::Parity::Even),
+        other => Err(DescriptorError::InvalidParity(other)),
+    }
+}
+
+fn parse_stop_bits(c: char) -> Result<serialport::StopBits, DescriptorError> {
+    match c {
+        '1' => Ok(serialport::StopBits::One),
+        '2' => Ok(serialport::StopBits::Two),
+        other => Err(DescriptorError::InvalidStopBits(other)),
+    }
+}
+
+fn parse_flow_control(s: &str) -> Result<serialport::FlowControl, DescriptorError> {
+    match s.to_ascii_lowercase().as_str() {
+        "none" => Ok(serialport::FlowControl::None),
+        "software" => Ok(serialport::FlowControl::Software),
+        "hardware" => Ok(serialport::FlowControl::Hardware),
+        other => Err(DescriptorError::InvalidFlowControl(other.to_string())),
+    }
 }
@@ -0,0 +1,150 @@
+// -- pluggable transport behind the core api
+// lets `SharedConnection` be generic over anything that looks enough like
+// a serial port to drive read/write/disconnect, so the retry and timeout
+// logic in `api` can be unit-tested without physical hardware
+
+use crate::serial::{ReadMode, SerialConnection};
+use std::io::{self, Write};
+use std::time::Duration;
+
+/// the subset of [`SerialConnection`] behavior the core `api` functions
+/// depend on
+pub trait Transport: Write + Send {
+    /// a single read attempt: return `Ok(0)` if nothing is available yet
+    /// rather than blocking, so callers holding a shared lock can release
+    /// it between attempts (mirrors [`SerialConnection::read_once`])
+    fn read_once(&mut self, buf: &mut [u8]) -> io::Result<usize>;
+
+    fn disconnect(self) -> io::Result<()>
+    where
+        Self: Sized;
+
+    /// how many bytes the api read loop should accumulate before returning;
+    /// defaults to `Any` (return as soon as `read_once` yields anything),
+    /// overridden by [`SerialConnection::set_read_mode`]
+    fn read_mode(&self) -> ReadMode {
+        ReadMode::Any
+    }
+
+    /// how much to scale the read deadline by the requested buffer length;
+    /// defaults to no scaling, overridden by
+    /// [`SerialConnection::set_read_timeout_mult`]
+    fn read_timeout_mult(&self) -> Duration {
+        Duration::ZERO
+    }
+}
+
+impl Transport for SerialConnection {
+    fn read_once(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        SerialConnection::read_once(self, buf)
+    }
+
+    fn disconnect(self) -> io::Result<()> {
+        SerialConnection::disconnect(self)
+    }
+
+    fn read_mode(&self) -> ReadMode {
+        SerialConnection::read_mode(self)
+    }
+
+    fn read_timeout_mult(&self) -> Duration {
+        SerialConnection::read_timeout_mult(self)
+    }
+}
+
+/// in-memory [`Transport`] for unit tests: preloaded with scripted read
+/// data via [`MockTransport::push_read`], records everything written in
+/// [`MockTransport::written`], and can be told to fail the Nth read/write
+/// with a given [`io::ErrorKind`] via `fail_read_on`/`fail_write_on`
+#[cfg(feature = "test-util")]
+pub struct MockTransport {
+    read_script: std::collections::VecDeque<Vec<u8>>,
+    pub written: Vec<u8>,
+    read_calls: usize,
+    write_calls: usize,
+    fail_read_on: Option<(usize, io::ErrorKind)>,
+    fail_write_on: Option<(usize, io::ErrorKind)>,
+}
+
+#[cfg(feature = "test-util")]
+impl MockTransport {
+    pub fn new() -> Self {
+        Self {
+            read_script: std::collections::VecDeque::new(),
+            written: Vec::new(),
+            read_calls: 0,
+            write_calls: 0,
+            fail_read_on: None,
+            fail_write_on: None,
+        }
+    }
+
+    /// queue a chunk of bytes to be returned by a future `read_once` call
+    pub fn push_read(&mut self, bytes: impl Into<Vec<u8>>) -> &mut Self {
+        self.read_script.push_back(bytes.into());
+        self
+    }
+
+    /// make the Nth (1-indexed) `read_once` call fail with `kind`
+    pub fn fail_read_on(&mut self, call: usize, kind: io::ErrorKind) -> &mut Self {
+        self.fail_read_on = Some((call, kind));
+        self
+    }
+
+    /// make the Nth (1-indexed) `write` call fail with `kind`
+    pub fn fail_write_on(&mut self, call: usize, kind: io::ErrorKind) -> &mut Self {
+        self.fail_write_on = Some((call, kind));
+        self
+    }
+}
+
+#[cfg(feature = "test-util")]
+impl Default for MockTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "test-util")]
+impl Transport for MockTransport {
+    fn read_once(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.read_calls += 1;
+        if let Some((call, kind)) = self.fail_read_on {
+            if call == self.read_calls {
+                return Err(io::Error::new(kind, "[mock] scripted read failure"));
+            }
+        }
+
+        match self.read_script.pop_front() {
+            Some(chunk) => {
+                let n = chunk.len().min(buf.len());
+                buf[..n].copy_from_slice(&chunk[..n]);
+                Ok(n)
+            }
+            None => Ok(0),
+        }
+    }
+
+    fn disconnect(self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "test-util")]
+impl Write for MockTransport {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_calls += 1;
+        if let Some((call, kind)) = self.fail_write_on {
+            if call == self.write_calls {
+                return Err(io::Error::new(kind, "[mock] scripted write failure"));
+            }
+        }
+
+        self.written.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}